@@ -17,6 +17,11 @@ from .reward_functions import get_reward_functions, RewardFunctionScore
 from .session import Session
 from .task import Task
 
+# Version of the JSON payload written by CurriculumLearning.save_state().
+CURRICULUM_STATE_VERSION = 1
+# Version assumed for state files that have no format_version field.
+_UNVERSIONED_STATE_VERSION = 1
+
 
 class CurriculumLearning:
 
@@ -1151,6 +1156,179 @@ class CurriculumLearning:
             "sliding_window_stats": success_stats,
         }
 
+    def save_state(self, path: str) -> None:
+        """Save curriculum progression state to a JSON file.
+
+        Persists the current level, step counters, level-change cooldown,
+        sliding success windows and the session's task history so a resumed
+        run continues with the same curriculum level, warmup/gating behaviour
+        and recency weighting in `get_prompt()` instead of restarting from
+        level 0. Task instances and generations are not saved.
+
+        Args:
+            path: Destination JSON file path
+        """
+        state = {
+            "format_version": CURRICULUM_STATE_VERSION,
+            "current_level": self.current_level,
+            "global_step": self.global_step,
+            "last_level_change_step": self.last_level_change_step,
+            "task_instance_counter": self.session.task_instance_counter,
+            "task_history": list(self.session.task_history),
+            "success_windows": {
+                str(level): list(window)
+                for level, window in self.success_windows.items()
+            },
+        }
+        state_path = Path(path)
+        state_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(state_path, "w", encoding="utf-8") as f:
+            json.dump(state, f, indent=2)
+
+    def load_state(self, path: str) -> None:
+        """Restore curriculum progression state saved by `save_state()`.
+
+        Success windows are rebuilt with this instance's `window_size`, so
+        only the most recent entries are kept if the window was shrunk.
+        Files without `format_version` are treated as version 1. Every field
+        is checked for presence, type and range before any state is changed,
+        so a bad file leaves this instance untouched.
+
+        Args:
+            path: JSON file path written by `save_state()`
+
+        Raises:
+            ValueError: If the file was written by a newer, unsupported version,
+                is missing a required key, or has a field of the wrong type or
+                out of range
+        """
+        with open(path, "r", encoding="utf-8") as f:
+            state = json.load(f)
+
+        if not isinstance(state, dict):
+            raise ValueError(
+                f"Curriculum state file {path} must contain a JSON object, "
+                f"got {type(state).__name__}"
+            )
+
+        version = state.get("format_version", _UNVERSIONED_STATE_VERSION)
+        if isinstance(version, bool) or not isinstance(version, int):
+            raise ValueError(
+                f"Curriculum state format_version must be an integer, got {version!r}"
+            )
+        if version > CURRICULUM_STATE_VERSION:
+            raise ValueError(
+                f"Unsupported curriculum state format_version {version} "
+                f"(latest supported: {CURRICULUM_STATE_VERSION})"
+            )
+        if version < CURRICULUM_STATE_VERSION:
+            state = self._migrate_state(state, version)
+
+        required_keys = [
+            "current_level",
+            "global_step",
+            "last_level_change_step",
+            "task_instance_counter",
+            "task_history",
+            "success_windows",
+        ]
+        missing_keys = [key for key in required_keys if key not in state]
+        if missing_keys:
+            raise ValueError(
+                f"Curriculum state file {path} is missing keys: "
+                f"{', '.join(missing_keys)}"
+            )
+
+        def parse_int(value: Any, key: str, minimum: Optional[int] = None) -> int:
+            if isinstance(value, bool) or not isinstance(value, int):
+                raise ValueError(
+                    f"Curriculum state key '{key}' must be an integer, got {value!r}"
+                )
+            if minimum is not None and value < minimum:
+                raise ValueError(
+                    f"Curriculum state key '{key}' must be >= {minimum}, got {value}"
+                )
+            return value
+
+        def parse_level(value: Any, key: str) -> int:
+            # Levels are clamped to 0-6 in _update_level()
+            if isinstance(value, bool) or not isinstance(value, int):
+                raise ValueError(
+                    f"Curriculum state key '{key}' must be an integer level, "
+                    f"got {value!r}"
+                )
+            if not 0 <= value <= 6:
+                raise ValueError(
+                    f"Curriculum state key '{key}' must be a level in 0-6, got {value}"
+                )
+            return value
+
+        current_level = parse_level(state["current_level"], "current_level")
+        global_step = parse_int(state["global_step"], "global_step", minimum=0)
+        last_level_change_step = parse_int(
+            state["last_level_change_step"], "last_level_change_step"
+        )
+        task_instance_counter = parse_int(
+            state["task_instance_counter"], "task_instance_counter", minimum=0
+        )
+
+        task_history = state["task_history"]
+        if not isinstance(task_history, list) or not all(
+            isinstance(tid, str) for tid in task_history
+        ):
+            raise ValueError(
+                "Curriculum state key 'task_history' must be a list of task ID strings"
+            )
+
+        raw_windows = state["success_windows"]
+        if not isinstance(raw_windows, dict):
+            raise ValueError(
+                "Curriculum state key 'success_windows' must be an object mapping "
+                f"levels to lists, got {type(raw_windows).__name__}"
+            )
+        success_windows: Dict[int, deque] = {}
+        for raw_level, window in raw_windows.items():
+            key = f"success_windows.{raw_level}"
+            try:
+                level = int(raw_level)
+            except ValueError:
+                raise ValueError(
+                    f"Curriculum state key '{key}' must use an integer level"
+                ) from None
+            level = parse_level(level, key)
+            if not isinstance(window, list) or not all(
+                isinstance(entry, int)
+                and not isinstance(entry, bool)
+                and entry in (0, 1)
+                for entry in window
+            ):
+                raise ValueError(
+                    f"Curriculum state key '{key}' must be a list of 0/1 values"
+                )
+            success_windows[level] = deque(window, maxlen=self.window_size)
+
+        self.current_level = current_level
+        self.global_step = global_step
+        self.last_level_change_step = last_level_change_step
+        self.session.task_instance_counter = task_instance_counter
+        self.session.task_history = task_history
+        self.success_windows = success_windows
+
+    def _migrate_state(self, state: Dict[str, Any], version: int) -> Dict[str, Any]:
+        """Upgrade a state payload from `version` to `CURRICULUM_STATE_VERSION`.
+
+        Version 1 is the first format, so no upgrade steps exist yet. When the
+        format changes, bump `CURRICULUM_STATE_VERSION` and add a step here
+        that converts the previous version's payload.
+
+        Raises:
+            ValueError: If no upgrade path exists from `version`
+        """
+        raise ValueError(
+            f"No migration from curriculum state format_version {version} "
+            f"to {CURRICULUM_STATE_VERSION}"
+        )
+
     def get_aux_reward_scores(
         self,
         task: "Task",
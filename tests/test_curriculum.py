@@ -4,6 +4,7 @@ import tempfile
 import os
 from unittest.mock import patch, MagicMock, mock_open
 from infinite_rl.curriculum import (
+    CURRICULUM_STATE_VERSION,
     CurriculumLearning,
     Task,
     Session,
@@ -1013,6 +1014,193 @@ class TestCurriculumLearning(unittest.TestCase):
         self.assertIsInstance(stats["available_tasks_by_level"], dict)
         self.assertIsInstance(stats["truthy_tasks_count"], int)
 
+    def test_save_and_load_state_round_trip(self):
+        """Test that curriculum progression state survives save/load."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+
+        cl = CurriculumLearning(window_size=10)
+        cl.current_level = 2
+        cl.global_step = 40
+        cl.last_level_change_step = 35
+        cl.session.task_instance_counter = 17
+        cl.session.task_history = ["math_3_15", "puzzle_js_Foo_16"]
+        for score in [1.0, 0.0, 1.0]:
+            cl._track_success_group(2, [score])
+        cl._track_success_group(1, [0.0])
+        cl.save_state(state_file)
+
+        restored = CurriculumLearning(window_size=10)
+        restored.load_state(state_file)
+
+        self.assertEqual(restored.current_level, 2)
+        self.assertEqual(restored.global_step, 40)
+        self.assertEqual(restored.last_level_change_step, 35)
+        self.assertEqual(restored.session.task_instance_counter, 17)
+        self.assertEqual(
+            restored.session.task_history, ["math_3_15", "puzzle_js_Foo_16"]
+        )
+        self.assertEqual(
+            restored.session._get_recent_task_ids(), ["math_3", "puzzle_js_Foo"]
+        )
+        self.assertEqual(list(restored.success_windows[2]), [1, 0, 1])
+        self.assertEqual(list(restored.success_windows[1]), [0])
+        self.assertEqual(restored.get_success_rate(2), cl.get_success_rate(2))
+
+    def test_load_state_respects_window_size(self):
+        """Test that restored windows are capped at the current window_size."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+
+        cl = CurriculumLearning(window_size=10)
+        for i in range(10):
+            cl._track_success_group(0, [1.0 if i >= 7 else 0.0])
+        cl.save_state(state_file)
+
+        restored = CurriculumLearning(window_size=3)
+        restored.load_state(state_file)
+
+        self.assertEqual(list(restored.success_windows[0]), [1, 1, 1])
+        self.assertEqual(restored.success_windows[0].maxlen, 3)
+
+    def test_save_state_writes_format_version(self):
+        """Test that saved state carries the current format version."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+
+        CurriculumLearning().save_state(state_file)
+
+        with open(state_file) as f:
+            state = json.load(f)
+        self.assertEqual(state["format_version"], CURRICULUM_STATE_VERSION)
+
+    def test_load_state_accepts_unversioned_state(self):
+        """Test that state files without format_version load as version 1."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        with open(state_file, "w") as f:
+            json.dump(
+                {
+                    "current_level": 1,
+                    "global_step": 12,
+                    "last_level_change_step": 8,
+                    "task_instance_counter": 5,
+                    "task_history": ["math_0_4"],
+                    "success_windows": {"1": [1, 0]},
+                },
+                f,
+            )
+
+        cl = CurriculumLearning()
+        cl.load_state(state_file)
+
+        self.assertEqual(cl.current_level, 1)
+        self.assertEqual(cl.global_step, 12)
+        self.assertEqual(cl.last_level_change_step, 8)
+        self.assertEqual(cl.session.task_instance_counter, 5)
+        self.assertEqual(cl.session.task_history, ["math_0_4"])
+        self.assertEqual(list(cl.success_windows[1]), [1, 0])
+
+    def test_load_state_missing_key_leaves_state_unchanged(self):
+        """Test that an incomplete state file raises without partial updates."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        saved = CurriculumLearning()
+        saved.current_level = 3
+        saved.global_step = 50
+        saved.save_state(state_file)
+
+        with open(state_file) as f:
+            state = json.load(f)
+        del state["success_windows"]
+        with open(state_file, "w") as f:
+            json.dump(state, f)
+
+        cl = CurriculumLearning()
+        with self.assertRaisesRegex(ValueError, "success_windows"):
+            cl.load_state(state_file)
+
+        self.assertEqual(cl.current_level, 0)
+        self.assertEqual(cl.global_step, 0)
+
+    def test_load_state_malformed_window_leaves_state_unchanged(self):
+        """Test that non-0/1 window entries raise without partial updates."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        saved = CurriculumLearning()
+        saved.current_level = 3
+        saved.global_step = 50
+        saved.save_state(state_file)
+
+        with open(state_file) as f:
+            state = json.load(f)
+        state["success_windows"] = {"3": ["x", None, 5]}
+        with open(state_file, "w") as f:
+            json.dump(state, f)
+
+        cl = CurriculumLearning()
+        with self.assertRaisesRegex(ValueError, "success_windows.3"):
+            cl.load_state(state_file)
+
+        self.assertEqual(cl.current_level, 0)
+        self.assertEqual(cl.global_step, 0)
+        self.assertEqual(cl.success_windows, {})
+
+    def test_load_state_rejects_invalid_field_types(self):
+        """Test that wrong types and out-of-range levels raise ValueError."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        cl = CurriculumLearning()
+        cl.save_state(state_file)
+        with open(state_file) as f:
+            valid_state = json.load(f)
+
+        cases = {
+            "top-level list": [valid_state],
+            "windows list": {**valid_state, "success_windows": [1, 2]},
+            "null global_step": {**valid_state, "global_step": None},
+            "level out of range": {**valid_state, "current_level": 99},
+            "window level out of range": {
+                **valid_state,
+                "success_windows": {"99": [1]},
+            },
+            "task_history not strings": {**valid_state, "task_history": [1, 2]},
+        }
+        for name, payload in cases.items():
+            with self.subTest(name):
+                with open(state_file, "w") as f:
+                    json.dump(payload, f)
+                with self.assertRaises(ValueError):
+                    cl.load_state(state_file)
+
+    def test_load_state_reads_unversioned_state_as_version_1(self):
+        """Test that unversioned files are migrated from version 1 after a bump."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        cl = CurriculumLearning()
+        cl.save_state(state_file)
+
+        with open(state_file) as f:
+            state = json.load(f)
+        del state["format_version"]
+        with open(state_file, "w") as f:
+            json.dump(state, f)
+
+        with patch("infinite_rl.curriculum.CURRICULUM_STATE_VERSION", 2):
+            with patch.object(
+                CurriculumLearning, "_migrate_state", return_value=state
+            ) as mock_migrate:
+                cl.load_state(state_file)
+
+        mock_migrate.assert_called_once_with(state, 1)
+
+    def test_load_state_rejects_newer_format_version(self):
+        """Test that state from a newer format version is rejected."""
+        state_file = os.path.join(self.temp_dir, "curriculum_state.json")
+        cl = CurriculumLearning()
+        cl.save_state(state_file)
+
+        with open(state_file) as f:
+            state = json.load(f)
+        state["format_version"] = CURRICULUM_STATE_VERSION + 1
+        with open(state_file, "w") as f:
+            json.dump(state, f)
+
+        with self.assertRaises(ValueError):
+            cl.load_state(state_file)
+
     def test_invalid_task_type(self):
         """Test handling of invalid task type."""
         cl = CurriculumLearning()